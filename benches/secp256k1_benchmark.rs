@@ -0,0 +1,110 @@
+//! Criterion benchmark groups mirroring the three scenarios in `src/main.rs`
+//! (single-core, two-core split, multi-core), in the ed25519-zebra / redpallas style:
+//! `Throughput`-annotated groups swept with `bench_with_input` instead of a hand-timed loop.
+//! The two-core pipeline itself is shared with the CLI via `secp256k1_benchmark::run_two_core_pipeline`
+//! so the two entry points can't drift apart. Run with `cargo bench`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use secp256k1::Secp256k1;
+use secp256k1_benchmark::{generate_unique_message, run_two_core_pipeline, PipelineStop, WorkloadKind};
+
+fn bench_keygen(c: &mut Criterion) {
+    let secp = Secp256k1::new();
+    let mut group = c.benchmark_group("keygen");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("generate_keypair", |b| {
+        b.iter(|| secp.generate_keypair(&mut rand::thread_rng()))
+    });
+    group.finish();
+}
+
+fn bench_verify(c: &mut Criterion) {
+    let secp = Secp256k1::new();
+    let (secret_key, public_key) = secp.generate_keypair(&mut rand::thread_rng());
+    let msg = generate_unique_message(0);
+    let sig = secp.sign_ecdsa(&msg, &secret_key);
+
+    let mut group = c.benchmark_group("verify");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("verify_ecdsa", |b| {
+        b.iter(|| secp.verify_ecdsa(&msg, &sig, &public_key).unwrap())
+    });
+    group.finish();
+}
+
+fn bench_double_verify(c: &mut Criterion) {
+    let secp = Secp256k1::new();
+    let (secret_key, public_key) = secp.generate_keypair(&mut rand::thread_rng());
+    let msg = generate_unique_message(0);
+    let sig = secp.sign_ecdsa(&msg, &secret_key);
+
+    let mut group = c.benchmark_group("double_verify");
+    group.throughput(Throughput::Elements(2));
+    group.bench_function("verify_ecdsa_twice", |b| {
+        b.iter(|| {
+            secp.verify_ecdsa(&msg, &sig, &public_key).unwrap();
+            secp.verify_ecdsa(&msg, &sig, &public_key).unwrap();
+        })
+    });
+    group.finish();
+}
+
+/// Runs the same generate/verify pipeline as the CLI, `run_two_core_pipeline` in `src/lib.rs` (bounded
+/// `crossbeam_channel`, backpressure/starvation counters included), but stopped after a fixed
+/// element count instead of a wall-clock duration so Criterion can report elements/sec.
+fn bench_two_core_pipeline(c: &mut Criterion) {
+    let mut group = c.benchmark_group("two_core_pipeline");
+    for &batch_size in &[100usize, 1_000, 10_000] {
+        group.throughput(Throughput::Elements(batch_size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(batch_size), &batch_size, |b, &batch_size| {
+            b.iter(|| run_two_core_pipeline(PipelineStop::Count(batch_size), WorkloadKind::DistinctKeys));
+        });
+    }
+    group.finish();
+}
+
+/// Mirrors `run_multi_core_benchmark`, but swept over core counts so the result is a scaling
+/// curve instead of one number pinned to `num_cpus::get()`.
+fn bench_multi_core_scaling(c: &mut Criterion) {
+    let available = num_cpus::get();
+    let core_counts: Vec<usize> = [1, 2, 4, available]
+        .into_iter()
+        .filter(|&n| n >= 1 && n <= available)
+        .collect();
+
+    let mut group = c.benchmark_group("multi_core_scaling");
+    for &num_threads in &core_counts {
+        group.throughput(Throughput::Elements(num_threads as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(num_threads),
+            &num_threads,
+            |b, &num_threads| {
+                let pool = ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap();
+                b.iter(|| {
+                    pool.install(|| {
+                        (0..num_threads).into_par_iter().for_each(|thread_id| {
+                            let secp = Secp256k1::new();
+                            let (secret_key, public_key) = secp.generate_keypair(&mut rand::thread_rng());
+                            let msg = generate_unique_message(thread_id);
+                            let signature = secp.sign_ecdsa(&msg, &secret_key);
+                            secp.verify_ecdsa(&msg, &signature, &public_key).unwrap();
+                        });
+                    });
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_keygen,
+    bench_verify,
+    bench_double_verify,
+    bench_two_core_pipeline,
+    bench_multi_core_scaling
+);
+criterion_main!(benches);
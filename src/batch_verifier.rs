@@ -0,0 +1,119 @@
+//! Bulk ECDSA verification behind a common [`BatchVerifier`] trait, so the benchmark can swap
+//! in a GPU-backed implementation without touching the scenario that drives it. Mirrors the
+//! shape of Solana's `sigverify` module: parallel CPU verification by default, with signature
+//! checks offloaded to the GPU behind `--features=gpu`.
+
+use secp256k1::{ecdsa, Message, PublicKey, Secp256k1};
+
+/// One ECDSA signature to verify, already split into the pieces a batch verifier needs.
+#[derive(Clone)]
+pub struct VerifyItem {
+    pub public_key: PublicKey,
+    pub msg: Message,
+    pub sig: ecdsa::Signature,
+}
+
+/// Verifies a batch of signatures, returning one pass/fail result per item in order.
+pub trait BatchVerifier {
+    fn verify_many(&self, items: &[VerifyItem]) -> Vec<bool>;
+
+    /// Label used in benchmark output to identify which backend produced a result.
+    fn name(&self) -> &'static str;
+}
+
+/// Default backend: verifies the batch across all available cores with rayon. This is the
+/// "multi-core CPU path" the GPU backend is measured against.
+pub struct CpuBatchVerifier {
+    secp: Secp256k1<secp256k1::All>,
+}
+
+impl CpuBatchVerifier {
+    pub fn new() -> Self {
+        Self { secp: Secp256k1::new() }
+    }
+}
+
+impl Default for CpuBatchVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BatchVerifier for CpuBatchVerifier {
+    fn verify_many(&self, items: &[VerifyItem]) -> Vec<bool> {
+        use rayon::prelude::*;
+        items
+            .par_iter()
+            .map(|item| self.secp.verify_ecdsa(&item.msg, &item.sig, &item.public_key).is_ok())
+            .collect()
+    }
+
+    fn name(&self) -> &'static str {
+        "cpu-rayon"
+    }
+}
+
+#[cfg(feature = "gpu")]
+pub mod gpu {
+    //! CUDA-backed [`BatchVerifier`]. Packs every element into a contiguous `#[repr(C)]` array
+    //! and hands the whole batch to a single FFI call, writing pass/fail bytes back into a
+    //! caller-owned buffer. Compiled only with `--features=gpu`; the CUDA toolchain and
+    //! `verify_many` kernel are expected to be provided by the build (via a `build.rs` linking
+    //! the `cuda` crate, not included in this snapshot).
+
+    use super::{BatchVerifier, VerifyItem};
+
+    /// One verification job in the layout the CUDA kernel expects: a serialized compressed
+    /// public key, a compact (r, s) signature, and the 32-byte message digest.
+    #[repr(C)]
+    pub struct GpuVerifyElement {
+        pub pubkey: [u8; 33],
+        pub sig: [u8; 64],
+        pub digest: [u8; 32],
+    }
+
+    extern "C" {
+        /// Verifies `num` elements from `elems`, writing one pass (`1`) / fail (`0`) byte per
+        /// element into `out`. `out` must point to at least `num` bytes.
+        fn verify_many(elems: *const GpuVerifyElement, num: usize, out: *mut u8);
+    }
+
+    pub struct CudaBatchVerifier;
+
+    impl CudaBatchVerifier {
+        pub fn new() -> Self {
+            Self
+        }
+    }
+
+    impl Default for CudaBatchVerifier {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl BatchVerifier for CudaBatchVerifier {
+        fn verify_many(&self, items: &[VerifyItem]) -> Vec<bool> {
+            let elems: Vec<GpuVerifyElement> = items
+                .iter()
+                .map(|item| GpuVerifyElement {
+                    pubkey: item.public_key.serialize(),
+                    sig: item.sig.serialize_compact(),
+                    digest: *item.msg.as_ref(),
+                })
+                .collect();
+
+            let mut out = vec![0u8; elems.len()];
+            // SAFETY: `elems` and `out` are both valid contiguous buffers of the lengths passed,
+            // and stay alive for the duration of the call.
+            unsafe {
+                verify_many(elems.as_ptr(), elems.len(), out.as_mut_ptr());
+            }
+            out.into_iter().map(|byte| byte != 0).collect()
+        }
+
+        fn name(&self) -> &'static str {
+            "gpu-cuda"
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Hardware metadata and a machine-normalized score, so a `BenchReport` can be diffed across
+//! hosts in CI without clock-speed differences masking (or faking) a real regression. Follows
+//! the `sc_sysinfo` approach used by Polkadot: collect a reproducible hardware fingerprint
+//! alongside the raw numbers, rather than trusting ops/sec in isolation.
+
+use raw_cpuid::CpuId;
+use serde::Serialize;
+use sysinfo::System;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct CacheSizes {
+    pub l1_kb: Option<u64>,
+    pub l2_kb: Option<u64>,
+    pub l3_kb: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HardwareInfo {
+    pub cpu_model: String,
+    pub physical_cores: usize,
+    pub logical_cores: usize,
+    /// Base clock in MHz. Falls back to the OS-reported current frequency on CPUs that don't
+    /// expose CPUID leaf 0x16 (most non-Intel parts), in which case `base_mhz == max_mhz`.
+    pub base_mhz: u64,
+    /// Max (turbo) clock in MHz, as CPUID leaf 0x16 reports it; same fallback caveat as `base_mhz`.
+    pub max_mhz: u64,
+    pub cache_sizes: CacheSizes,
+}
+
+/// Captures CPU model, core/thread counts, clock speeds, and cache sizes for the host running
+/// the benchmark.
+pub fn collect_hardware_info() -> HardwareInfo {
+    let mut sys = System::new_all();
+    sys.refresh_cpu();
+
+    let cpus = sys.cpus();
+    let cpu_model = cpus.first().map(|cpu| cpu.brand().to_string()).unwrap_or_default();
+    let logical_cores = cpus.len();
+    let physical_cores = sys.physical_core_count().unwrap_or(logical_cores);
+    let reported_mhz = cpus.first().map(|cpu| cpu.frequency()).unwrap_or(0);
+
+    let cpuid = CpuId::new();
+    let (base_mhz, max_mhz) = cpuid
+        .get_processor_frequency_info()
+        .map(|info| (info.processor_base_frequency() as u64, info.processor_max_frequency() as u64))
+        .unwrap_or((reported_mhz, reported_mhz));
+
+    let cache_sizes = cpuid
+        .get_cache_parameters()
+        .map(|params| {
+            let mut sizes = CacheSizes::default();
+            for param in params {
+                let size_kb = (param.associativity()
+                    * param.physical_line_partitions()
+                    * param.coherency_line_size()
+                    * param.sets()) as u64
+                    / 1024;
+                match param.level() {
+                    1 => sizes.l1_kb = Some(sizes.l1_kb.unwrap_or(0) + size_kb),
+                    2 => sizes.l2_kb = Some(size_kb),
+                    3 => sizes.l3_kb = Some(size_kb),
+                    _ => {}
+                }
+            }
+            sizes
+        })
+        .unwrap_or_default();
+
+    HardwareInfo { cpu_model, physical_cores, logical_cores, base_mhz, max_mhz, cache_sizes }
+}
+
+/// Per-scenario keygen/verify/double-verify rates, in operations per second.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScenarioRates {
+    pub keygen_per_sec: f64,
+    pub verify_per_sec: f64,
+    pub double_verify_per_sec: f64,
+}
+
+/// The full result set for one benchmark run: enough to print a human-readable summary and to
+/// diff machine-to-machine without clock speed distorting the comparison.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchReport {
+    pub hardware: HardwareInfo,
+    pub single_core: ScenarioRates,
+    pub two_core: ScenarioRates,
+    pub multi_core: ScenarioRates,
+    /// `multi_core.verify_per_sec` divided by (max GHz × logical cores), so the same hardware
+    /// at a different clock speed scores the same and a genuine regression doesn't hide behind
+    /// a faster test machine.
+    pub verifications_per_ghz_per_core: f64,
+}
+
+impl BenchReport {
+    pub fn new(hardware: HardwareInfo, single_core: ScenarioRates, two_core: ScenarioRates, multi_core: ScenarioRates) -> Self {
+        let ghz = hardware.max_mhz as f64 / 1000.0;
+        let denom = ghz * hardware.logical_cores as f64;
+        let verifications_per_ghz_per_core = if denom > 0.0 { multi_core.verify_per_sec / denom } else { 0.0 };
+
+        Self { hardware, single_core, two_core, multi_core, verifications_per_ghz_per_core }
+    }
+}
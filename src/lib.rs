@@ -0,0 +1,394 @@
+//! Shared building blocks used by both the `secp256k1-benchmark` CLI (`src/main.rs`, a quick
+//! hand-timed run suitable for CI regression checks) and the Criterion harness
+//! (`benches/secp256k1_benchmark.rs`, for statistically rigorous local profiling). Keeping the
+//! primitives here means both entry points exercise the exact same crypto operations.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{bounded, RecvTimeoutError, TrySendError};
+use num_bigint::BigUint;
+use secp256k1::{schnorr, Keypair, Message, Parity, PublicKey, Scalar, Secp256k1, SecretKey, XOnlyPublicKey};
+use sha2::{Digest, Sha256};
+
+pub mod batch_verifier;
+pub mod hardware_report;
+
+/// Thread-safe operation counters shared across rayon workers in the multi-core scenario.
+#[derive(Default)]
+pub struct OpCounts {
+    pub generations: AtomicUsize,
+    pub verifications: AtomicUsize,
+    pub double_verifications: AtomicUsize,
+}
+
+/// Selects how keys are supplied to a verification scenario, since verification cost differs
+/// depending on whether point precomputation and cache locality can kick in across calls.
+/// Mirrors the `sigs_with_distinct_pubkeys` / `sigs_with_same_pubkey` split in ed25519-zebra.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkloadKind {
+    /// Every signature is verified under a freshly generated key.
+    DistinctKeys,
+    /// Every signature is verified under the same fixed keypair, signing many messages.
+    RepeatedKey,
+}
+
+/// Builds a deterministic-but-distinct 32-byte message for iteration `counter`, so repeated
+/// runs don't accidentally sign and verify the exact same digest every time.
+pub fn generate_unique_message(counter: usize) -> Message {
+    let mut msg_bytes = [0u8; 32];
+    msg_bytes[0..8].copy_from_slice(&counter.to_le_bytes());
+    for (i, byte) in msg_bytes.iter_mut().enumerate().skip(8) {
+        *byte = (counter >> (i % 8)) as u8;
+    }
+    Message::from_digest(msg_bytes)
+}
+
+// ------------------------------------------------------------------------------------------
+// Two-core generate/verify pipeline
+// ------------------------------------------------------------------------------------------
+
+/// When to stop the [`run_two_core_pipeline`] generator: run for a wall-clock duration (the CLI
+/// scenario) or until exactly `n` items have been produced (the Criterion scenario, which wants
+/// a fixed element count per iteration so `Throughput::Elements` stays meaningful).
+#[derive(Debug, Clone, Copy)]
+pub enum PipelineStop {
+    Duration(Duration),
+    Count(usize),
+}
+
+impl PipelineStop {
+    fn is_done(&self, start: Instant, produced: usize) -> bool {
+        match self {
+            PipelineStop::Duration(d) => start.elapsed() >= *d,
+            PipelineStop::Count(n) => produced >= *n,
+        }
+    }
+}
+
+/// Result of [`run_two_core_pipeline`], including the producer/consumer imbalance metrics that
+/// identify which side of the split is the bottleneck.
+#[derive(Debug, Default)]
+pub struct TwoCoreResult {
+    pub gen_count: usize,
+    pub verify_count: usize,
+    pub double_verify_count: usize,
+    /// How many times the generator found the channel full and had to block on `send`.
+    pub producer_blocked_count: usize,
+    /// How many times the verifier drained an empty channel and had to wait for more input.
+    pub consumer_starved_count: usize,
+}
+
+/// Bounds on the two-core pipeline: the channel applies backpressure to the generator once it
+/// holds this many items, and the verifier never processes more than this many at once, so
+/// memory stays flat instead of growing for the whole run (as an unbounded history `Vec` would).
+pub const CHANNEL_CAPACITY: usize = 100_000;
+pub const DRAIN_BATCH_CAP: usize = 100_000;
+pub const STARVE_TIMEOUT: Duration = Duration::from_millis(1);
+
+/// One thread continuously generates keypairs and signs a message with each; a second thread
+/// verifies them (with a double-verify every third signature, mirroring the single- and
+/// multi-core scenarios). The two communicate over a bounded `crossbeam_channel` so a slow
+/// verifier applies backpressure to the generator instead of an unbounded queue growing for the
+/// whole run. Shared by the CLI (`stop: PipelineStop::Duration`) and the Criterion harness
+/// (`stop: PipelineStop::Count`), so both exercise the exact same pipeline.
+pub fn run_two_core_pipeline(stop: PipelineStop, workload: WorkloadKind) -> TwoCoreResult {
+    let (tx, rx) = bounded(CHANNEL_CAPACITY);
+    let producer_blocked = Arc::new(AtomicUsize::new(0));
+    let consumer_starved = Arc::new(AtomicUsize::new(0));
+
+    // Under `RepeatedKey` the generator signs every message with the same keypair; under
+    // `DistinctKeys` it generates a fresh one per message.
+    let repeated_key = match workload {
+        WorkloadKind::RepeatedKey => Some(Secp256k1::new().generate_keypair(&mut rand::thread_rng())),
+        WorkloadKind::DistinctKeys => None,
+    };
+
+    // Generator thread
+    let gen_blocked = Arc::clone(&producer_blocked);
+    let gen_thread = thread::spawn(move || {
+        let secp = Secp256k1::new();
+        let start = Instant::now();
+        let mut count = 0;
+
+        while !stop.is_done(start, count) {
+            let (secret_key, public_key) = repeated_key.unwrap_or_else(|| secp.generate_keypair(&mut rand::thread_rng()));
+            let msg = generate_unique_message(count);
+            let signature = secp.sign_ecdsa(&msg, &secret_key);
+
+            match tx.try_send((public_key, signature, msg)) {
+                Ok(()) => {}
+                Err(TrySendError::Full(item)) => {
+                    // The channel is at capacity: the verifier can't keep up. Block until it
+                    // drains, applying backpressure instead of growing an unbounded queue.
+                    gen_blocked.fetch_add(1, Ordering::Relaxed);
+                    if tx.send(item).is_err() {
+                        break;
+                    }
+                }
+                Err(TrySendError::Disconnected(_)) => break,
+            }
+            count += 1;
+        }
+        count
+    });
+
+    // Verifier thread
+    let verify_starved = Arc::clone(&consumer_starved);
+    let verify_thread = thread::spawn(move || {
+        let secp = Secp256k1::new();
+        let mut verify_count = 0;
+        let mut double_verify_count = 0;
+        let start = Instant::now();
+
+        while !stop.is_done(start, verify_count) {
+            // Drain up to `DRAIN_BATCH_CAP` queued items into a fixed-size batch, verify it, and
+            // drop it, instead of accumulating history across the whole run.
+            let mut batch = Vec::new();
+            match rx.recv_timeout(STARVE_TIMEOUT) {
+                Ok(item) => batch.push(item),
+                Err(RecvTimeoutError::Timeout) => {
+                    verify_starved.fetch_add(1, Ordering::Relaxed);
+                    continue;
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            while batch.len() < DRAIN_BATCH_CAP {
+                match rx.try_recv() {
+                    Ok(item) => batch.push(item),
+                    Err(_) => break,
+                }
+            }
+
+            for (pub_key, sig, msg) in &batch {
+                secp.verify_ecdsa(msg, sig, pub_key).unwrap();
+                verify_count += 1;
+
+                // Double verification (every third iteration)
+                if verify_count % 3 == 0 {
+                    secp.verify_ecdsa(msg, sig, pub_key).unwrap();
+                    secp.verify_ecdsa(msg, sig, pub_key).unwrap();
+                    double_verify_count += 2;
+                }
+            }
+        }
+        (verify_count, double_verify_count)
+    });
+
+    let gen_count = gen_thread.join().unwrap();
+    let (verify_count, double_verify_count) = verify_thread.join().unwrap();
+
+    TwoCoreResult {
+        gen_count,
+        verify_count,
+        double_verify_count,
+        producer_blocked_count: producer_blocked.load(Ordering::Relaxed),
+        consumer_starved_count: consumer_starved.load(Ordering::Relaxed),
+    }
+}
+
+// ------------------------------------------------------------------------------------------
+// Batch Schnorr verification
+// ------------------------------------------------------------------------------------------
+
+/// Batch sizes swept by the batch-verification benchmark, chosen to show the crossover point
+/// where aggregating checks starts to beat verifying signatures one at a time.
+pub const BATCH_SIZES: [usize; 4] = [8, 16, 32, 64];
+
+/// The secp256k1 group order `n`, used to reduce the scalar arithmetic performed while
+/// aggregating a batch (the `secp256k1::Scalar` type itself is an opaque tweak and does not
+/// expose addition/multiplication, so the sums are carried out on big integers and only
+/// converted back to `Scalar` at the end).
+const CURVE_ORDER: [u8; 32] = [
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE,
+    0xBA, 0xAE, 0xDC, 0xE6, 0xAF, 0x48, 0xA0, 0x3B,
+    0xBF, 0xD2, 0x5E, 0x8C, 0xD0, 0x36, 0x41, 0x41,
+];
+
+/// A single Schnorr signature plus the pieces needed to fold it into a batch: its challenge
+/// scalar `e = H(R || P || m)` and the nonce point `R`, both pre-extracted so the aggregation
+/// loop doesn't need to re-derive them per scalar `a_i`.
+pub struct SchnorrItem {
+    pub public_key: XOnlyPublicKey,
+    pub r: PublicKey,
+    pub s: Scalar,
+    pub e: Scalar,
+    pub msg: Message,
+    pub sig: schnorr::Signature,
+}
+
+fn curve_order() -> BigUint {
+    BigUint::from_bytes_be(&CURVE_ORDER)
+}
+
+fn scalar_to_biguint(s: &Scalar) -> BigUint {
+    BigUint::from_bytes_be(&s.to_be_bytes())
+}
+
+fn biguint_to_scalar(n: &BigUint) -> Scalar {
+    let reduced = n % curve_order();
+    let mut bytes = [0u8; 32];
+    let reduced_bytes = reduced.to_bytes_be();
+    bytes[32 - reduced_bytes.len()..].copy_from_slice(&reduced_bytes);
+    Scalar::from_be_bytes(bytes).expect("reduced mod n fits in a Scalar")
+}
+
+/// Draws a uniformly random non-zero scalar, used for every `a_i` but the first (which is
+/// fixed to 1 so a forger can't cancel the whole batch with an all-zero combination).
+pub fn random_nonzero_scalar(rng: &mut impl rand::RngCore) -> Scalar {
+    loop {
+        let mut buf = [0u8; 32];
+        rng.fill_bytes(&mut buf);
+        if let Ok(scalar) = Scalar::from_be_bytes(buf) {
+            if scalar != Scalar::ZERO {
+                return scalar;
+            }
+        }
+    }
+}
+
+/// BIP340 tagged hash: `SHA256(SHA256(tag) || SHA256(tag) || data)`.
+fn tagged_hash(tag: &str, data: &[u8]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Computes the BIP340 challenge `e = H(R.x || P.x || m)` as a scalar. The hash output is
+/// taken as-is; the ~2^-128 chance it doesn't reduce mod the curve order is not worth handling
+/// in a benchmark.
+fn schnorr_challenge(r: &XOnlyPublicKey, p: &XOnlyPublicKey, msg: &Message) -> Scalar {
+    let mut data = Vec::with_capacity(96);
+    data.extend_from_slice(&r.serialize());
+    data.extend_from_slice(&p.serialize());
+    data.extend_from_slice(msg.as_ref());
+    let e_bytes = tagged_hash("BIP0340/challenge", &data);
+    Scalar::from_be_bytes(e_bytes).expect("sha256 output reduces mod n with overwhelming probability")
+}
+
+pub fn generate_schnorr_item(secp: &Secp256k1<secp256k1::All>, counter: usize) -> SchnorrItem {
+    let keypair = Keypair::new(secp, &mut rand::thread_rng());
+    let (xonly, _parity) = keypair.x_only_public_key();
+    let msg = generate_unique_message(counter);
+    let sig = secp.sign_schnorr_with_rng(&msg, &keypair, &mut rand::thread_rng());
+
+    let sig_bytes = sig.as_ref();
+    let r_xonly = XOnlyPublicKey::from_slice(&sig_bytes[..32]).expect("valid nonce point");
+    // BIP340 verification always lifts R and P to their even-y representative.
+    let r = r_xonly.public_key(Parity::Even);
+    let s = Scalar::from_be_bytes(sig_bytes[32..64].try_into().unwrap()).expect("valid scalar");
+    let e = schnorr_challenge(&r_xonly, &xonly, &msg);
+
+    SchnorrItem { public_key: xonly, r, s, e, msg, sig }
+}
+
+/// Verifies `items` as a single aggregated check:
+///
+/// `(Σ a_i·s_i)·G == Σ a_i·R_i + Σ (a_i·e_i)·P_i`
+///
+/// with `a_1 = 1` and the remaining `a_i` drawn independently at random. Returns the index of
+/// a bad signature (found via fallback per-signature verification) if the batch doesn't check
+/// out; a failed aggregate check only proves that *some* signature in the batch is invalid.
+pub fn verify_schnorr_batch(secp: &Secp256k1<secp256k1::All>, items: &[SchnorrItem]) -> Result<(), usize> {
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    let mut rng = rand::thread_rng();
+    let order = curve_order();
+
+    let mut s_acc = BigUint::from(0u32);
+    let mut rhs_terms: Vec<PublicKey> = Vec::with_capacity(items.len() * 2);
+
+    for (i, item) in items.iter().enumerate() {
+        let a_i = if i == 0 { Scalar::ONE } else { random_nonzero_scalar(&mut rng) };
+        let a_big = scalar_to_biguint(&a_i);
+
+        s_acc = (s_acc + &a_big * scalar_to_biguint(&item.s)) % &order;
+
+        rhs_terms.push(
+            item.r
+                .mul_tweak(secp, &biguint_to_scalar(&a_big))
+                .expect("a_i is non-zero"),
+        );
+
+        let ae_big = (&a_big * scalar_to_biguint(&item.e)) % &order;
+        let p = item.public_key.public_key(Parity::Even);
+        rhs_terms.push(p.mul_tweak(secp, &biguint_to_scalar(&ae_big)).expect("tweak scalar"));
+    }
+
+    let mut s_bytes = [0u8; 32];
+    let s_acc_bytes = s_acc.to_bytes_be();
+    s_bytes[32 - s_acc_bytes.len()..].copy_from_slice(&s_acc_bytes);
+    let lhs = SecretKey::from_slice(&s_bytes)
+        .expect("scalar sum over a random batch is non-zero")
+        .public_key(secp);
+
+    let rhs_refs: Vec<&PublicKey> = rhs_terms.iter().collect();
+    let rhs = PublicKey::combine_keys(&rhs_refs).expect("batch sum is not the point at infinity");
+
+    if lhs == rhs {
+        return Ok(());
+    }
+
+    for (i, item) in items.iter().enumerate() {
+        if secp.verify_schnorr(&item.sig, &item.msg, &item.public_key).is_err() {
+            return Err(i);
+        }
+    }
+    // Every individual signature checks out but the aggregate didn't: an adversarial
+    // cancellation landed despite the random coefficients. Report the first index as a
+    // best-effort answer.
+    Err(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Flips a bit in `item`'s `s` scalar (and the matching bytes of `sig`), producing an
+    /// otherwise well-formed but invalid signature over the same message and key.
+    fn corrupt(item: &SchnorrItem) -> SchnorrItem {
+        let mut sig_bytes = *item.sig.as_ref();
+        sig_bytes[63] ^= 0x01;
+        let sig = schnorr::Signature::from_slice(&sig_bytes).expect("still a well-formed signature");
+        let s = Scalar::from_be_bytes(sig_bytes[32..64].try_into().unwrap())
+            .expect("flipped scalar still reduces mod n with overwhelming probability");
+
+        SchnorrItem { public_key: item.public_key, r: item.r, s, e: item.e, msg: item.msg, sig }
+    }
+
+    #[test]
+    fn empty_batch_is_ok() {
+        let secp = Secp256k1::new();
+        assert_eq!(verify_schnorr_batch(&secp, &[]), Ok(()));
+    }
+
+    #[test]
+    fn single_valid_signature_is_ok() {
+        let secp = Secp256k1::new();
+        let item = generate_schnorr_item(&secp, 0);
+        assert_eq!(verify_schnorr_batch(&secp, &[item]), Ok(()));
+    }
+
+    #[test]
+    fn single_invalid_signature_is_rejected() {
+        let secp = Secp256k1::new();
+        let item = corrupt(&generate_schnorr_item(&secp, 0));
+        assert_eq!(verify_schnorr_batch(&secp, &[item]), Err(0));
+    }
+
+    #[test]
+    fn one_corrupted_signature_in_a_batch_is_localized() {
+        let secp = Secp256k1::new();
+        let mut items: Vec<SchnorrItem> = (0..8).map(|i| generate_schnorr_item(&secp, i)).collect();
+        items[3] = corrupt(&items[3]);
+        assert_eq!(verify_schnorr_batch(&secp, &items), Err(3));
+    }
+}